@@ -27,6 +27,8 @@ pub struct BuildConfig {
     pub log_dir: String,
     pub pdf_dir: String,
     pub output_filename: Option<String>,
+    pub timeout: Option<Duration>,
+    pub env: FxHashMap<String, String>,
 }
 
 #[derive(Debug)]
@@ -56,6 +58,7 @@ pub struct FormattingConfig {
     pub tex_formatter: Formatter,
     pub bib_formatter: Formatter,
     pub latex_indent: LatexIndentConfig,
+    pub bibtex: BibtexFormattingConfig,
     pub line_length: usize,
 }
 
@@ -74,6 +77,12 @@ pub struct LatexIndentConfig {
     pub replacement: Option<String>,
 }
 
+#[derive(Debug, Default)]
+pub struct BibtexFormattingConfig {
+    pub align_fields: bool,
+    pub field_order: Vec<String>,
+}
+
 #[derive(Debug, Default)]
 pub struct SymbolConfig {
     pub allowed_patterns: Vec<Regex>,
@@ -133,6 +142,8 @@ impl Default for BuildConfig {
             log_dir: String::from("."),
             pdf_dir: String::from("."),
             output_filename: None,
+            timeout: None,
+            env: FxHashMap::default(),
         }
     }
 }
@@ -155,6 +166,7 @@ impl Default for FormattingConfig {
             bib_formatter: Formatter::Server,
             line_length: 80,
             latex_indent: LatexIndentConfig::default(),
+            bibtex: BibtexFormattingConfig::default(),
         }
     }
 }
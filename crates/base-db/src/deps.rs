@@ -6,6 +6,6 @@ mod root;
 pub use self::{
     discover::{discover, watch},
     graph::{DirectLinkData, Edge, EdgeData, Graph, HOME_DIR},
-    project::{Project, parents},
+    project::{Project, build_root, parents},
     root::ProjectRoot,
 };
@@ -25,10 +25,10 @@ pub fn watch<T, C>(
 
     for root in roots {
         for uri in [&root.src_dir, &root.aux_dir, &root.log_dir, &root.pdf_dir] {
-            if let Ok(path) = uri.to_file_path() {
-                if watched_dirs.insert(path.clone()) {
-                    let _ = watcher.watch(&path, notify::RecursiveMode::NonRecursive);
-                }
+            if let Ok(path) = uri.to_file_path()
+                && watched_dirs.insert(path.clone())
+            {
+                let _ = watcher.watch(&path, notify::RecursiveMode::NonRecursive);
             }
         }
     }
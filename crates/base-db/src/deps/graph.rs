@@ -148,7 +148,7 @@ impl Graph {
             .chain(file_list.outputs.iter())
             .filter(|path| {
                 path.is_relative()
-                    || Language::from_path(&path) == Some(Language::Bib)
+                    || Language::from_path(path) == Some(Language::Bib)
                     || home_dir.is_some_and(|home_dir| path.starts_with(home_dir))
             })
             .filter_map(|path| working_dir.join(path.to_str()?).ok())
@@ -273,7 +273,7 @@ impl Graph {
             extension,
         );
 
-        self.add_artifact(workspace, start.source, &dir, extension);
+        self.add_artifact(workspace, start.source, dir, extension);
         self.add_artifact(workspace, start.source, &start.root.compile_dir, extension);
     }
 
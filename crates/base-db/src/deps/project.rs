@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
+use url::Url;
 
 use crate::{Document, Workspace};
 
@@ -37,3 +38,34 @@ pub fn parents<'a>(workspace: &'a Workspace, child: &'a Document) -> FxHashSet<&
         })
         .collect()
 }
+
+/// Returns the document that is compiled when building the given document.
+/// A `% !TEX root = ...` comment takes precedence over the dependency graph.
+pub fn build_root<'a>(workspace: &'a Workspace, document: &'a Document) -> &'a Document {
+    magic_root(document)
+        .and_then(|uri| workspace.lookup(&uri))
+        .or_else(|| parents(workspace, document).into_iter().next())
+        .unwrap_or(document)
+}
+
+/// Resolves the root document declared by a `% !TEX root = ...` comment
+/// at the beginning of the given document.
+fn magic_root(document: &Document) -> Option<Url> {
+    let path = document
+        .text
+        .lines()
+        .map(str::trim)
+        .take_while(|line| line.is_empty() || line.starts_with('%'))
+        .find_map(|line| {
+            let line = line.strip_prefix('%')?.trim_start().strip_prefix('!')?;
+            let (key, value) = line.split_once('=')?;
+            let mut words = key.split_whitespace();
+            let is_root = words.next()?.eq_ignore_ascii_case("tex")
+                && words.next()?.eq_ignore_ascii_case("root")
+                && words.next().is_none();
+
+            is_root.then(|| value.trim())
+        })?;
+
+    document.dir.as_ref()?.join(path).ok()
+}
@@ -60,7 +60,7 @@ impl Document {
                     &params.config.syntax,
                     &latex::SyntaxNode::new_root(green.clone()),
                 );
-                DocumentData::Tex(TexDocumentData { green, semantics })
+                DocumentData::Tex(Box::new(TexDocumentData { green, semantics }))
             }
             Language::Bib => {
                 let green = parser::parse_bibtex(&text);
@@ -140,7 +140,7 @@ impl std::hash::Hash for Document {
 
 #[derive(Debug, Clone)]
 pub enum DocumentData {
-    Tex(TexDocumentData),
+    Tex(Box<TexDocumentData>),
     Bib(BibDocumentData),
     Aux(AuxDocumentData),
     Log(LogDocumentData),
@@ -63,10 +63,10 @@ impl Semantics {
                 },
                 full_range: string.syntax().text_range(),
             });
-            if let Some(value) = string.value() {
-                if let Some(data) = TextFieldData::parse(&value, &self.expanded_defs) {
-                    self.expanded_defs.insert(name.text().into(), data.text);
-                }
+            if let Some(value) = string.value()
+                && let Some(data) = TextFieldData::parse(&value, &self.expanded_defs)
+            {
+                self.expanded_defs.insert(name.text().into(), data.text);
             }
         }
     }
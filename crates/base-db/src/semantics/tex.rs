@@ -9,7 +9,7 @@ use super::Span;
 use crate::semantics::tex::latex::SyntaxToken;
 
 fn maybe_prepend_prefix(
-    map: &Vec<(String, String)>,
+    map: &[(String, String)],
     command: &Option<SyntaxToken>,
     name: &Span,
 ) -> Span {
@@ -34,6 +34,7 @@ pub struct Semantics {
     pub commands: Vec<Span>,
     pub environments: Vec<Span>,
     pub theorem_definitions: Vec<TheoremDefinition>,
+    pub color_definitions: Vec<Span>,
     pub graphics_paths: FxHashSet<String>,
     pub can_be_root: bool,
     pub can_be_compiled: bool,
@@ -99,6 +100,8 @@ impl Semantics {
             self.process_environment(environment);
         } else if let Some(theorem_def) = latex::TheoremDefinition::cast(node.clone()) {
             self.process_theorem_definition(theorem_def);
+        } else if let Some(color_def) = latex::ColorDefinition::cast(node.clone()) {
+            self.process_color_definition(color_def);
         } else if let Some(graphics_path) = latex::GraphicsPath::cast(node.clone()) {
             self.process_graphics_path(graphics_path);
         } else if let Some(bibitem) = latex::BibItem::cast(node.clone()) {
@@ -145,7 +148,7 @@ impl Semantics {
         let Some(path) = import.file().and_then(|path| path.key()) else {
             return;
         };
-        let text = format!("{base_dir}{}", path.to_string());
+        let text = format!("{base_dir}{}", path);
         let range = latex::small_range(&path);
 
         self.links.push(Link {
@@ -347,6 +350,14 @@ impl Semantics {
         }
     }
 
+    fn process_color_definition(&mut self, color_def: latex::ColorDefinition) {
+        if let Some(name) = color_def.name()
+            && let Some(key) = name.key()
+        {
+            self.color_definitions.push(Span::from(&key));
+        }
+    }
+
     fn process_graphics_path(&mut self, graphics_path: latex::GraphicsPath) {
         for path in graphics_path.path_list().filter_map(|path| path.key()) {
             self.graphics_paths.insert(path.to_string());
@@ -354,10 +365,10 @@ impl Semantics {
     }
 
     fn process_bibitem(&mut self, bibitem: latex::BibItem) {
-        if let Some(name) = bibitem.name() {
-            if let Some(key) = name.key() {
-                self.bibitems.insert(Span::from(&key));
-            }
+        if let Some(name) = bibitem.name()
+            && let Some(key) = name.key()
+        {
+            self.bibitems.insert(Span::from(&key));
         }
     }
 }
@@ -105,10 +105,10 @@ impl Workspace {
             Owner::Server
         };
 
-        if let Some(document) = self.lookup_file(path) {
-            if document.text == text {
-                return Ok(());
-            }
+        if let Some(document) = self.lookup_file(path)
+            && document.text == text
+        {
+            return Ok(());
         }
 
         self.open(uri, text, language, owner, LineCol { line: 0, col: 0 });
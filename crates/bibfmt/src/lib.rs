@@ -6,6 +6,8 @@ pub struct Options {
     pub insert_spaces: bool,
     pub tab_size: usize,
     pub line_length: usize,
+    pub align_fields: bool,
+    pub field_order: Vec<String>,
 }
 
 impl Options {
@@ -24,6 +26,8 @@ impl Default for Options {
             insert_spaces: true,
             tab_size: 4,
             line_length: 80,
+            align_fields: false,
+            field_order: Vec::new(),
         }
     }
 }
@@ -76,6 +80,18 @@ impl<'a> Formatter<'a> {
         previous.line != current.line || previous.col < current.col
     }
 
+    fn field_position(&self, field: &bibtex::Field) -> usize {
+        field
+            .name_token()
+            .and_then(|name| {
+                self.options
+                    .field_order
+                    .iter()
+                    .position(|other| other.eq_ignore_ascii_case(name.text()))
+            })
+            .unwrap_or(self.options.field_order.len())
+    }
+
     fn base_align(&self) -> usize {
         self.output[self.output.rfind('\n').unwrap_or(0)..]
             .chars()
@@ -118,25 +134,31 @@ impl<'a> Formatter<'a> {
                     self.output.push_str(&key.to_string());
                     self.output.push(',');
                     self.output.push('\n');
-                    for field in entry.fields() {
-                        self.visit_node(field.syntax().clone());
+
+                    let mut fields: Vec<_> = entry.fields().collect();
+                    fields.sort_by_key(|field| self.field_position(field));
+
+                    let name_width = if self.options.align_fields {
+                        fields
+                            .iter()
+                            .filter_map(|field| field.name_token())
+                            .map(|name| name.text().chars().count())
+                            .max()
+                            .unwrap_or(0)
+                    } else {
+                        0
+                    };
+
+                    for field in fields {
+                        self.visit_field(field, name_width);
                     }
+
                     self.output.push('}');
                 }
             }
             bibtex::FIELD => {
                 let field = bibtex::Field::cast(parent).unwrap();
-                self.output.push_str(self.indent);
-                let name = field.name_token().unwrap();
-                self.output.push_str(name.text());
-                self.output.push_str(" = ");
-                if let Some(value) = field.value() {
-                    let count = name.text().chars().count();
-                    self.align.push(self.options.tab_size + count + 3);
-                    self.visit_node(value.syntax().clone());
-                    self.output.push(',');
-                    self.output.push('\n');
-                }
+                self.visit_field(field, 0);
             }
             kind if bibtex::Value::can_cast(kind) => {
                 let tokens: Vec<_> = parent
@@ -187,6 +209,25 @@ impl<'a> Formatter<'a> {
             _ => unreachable!(),
         }
     }
+
+    fn visit_field(&mut self, field: bibtex::Field, name_width: usize) {
+        self.output.push_str(self.indent);
+        let name = field.name_token().unwrap();
+        let count = name.text().chars().count();
+        self.output.push_str(name.text());
+        for _ in count..name_width {
+            self.output.push(' ');
+        }
+
+        self.output.push_str(" = ");
+        if let Some(value) = field.value() {
+            self.align
+                .push(self.options.tab_size + count.max(name_width) + 3);
+            self.visit_node(value.syntax().clone());
+            self.output.push(',');
+            self.output.push('\n');
+        }
+    }
 }
 
 #[cfg(test)]
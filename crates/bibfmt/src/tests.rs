@@ -5,10 +5,14 @@ use syntax::bibtex;
 use crate::Options;
 
 fn check(input: &str, expect: Expect) {
+    check_with_options(input, &Options::default(), expect);
+}
+
+fn check_with_options(input: &str, options: &Options, expect: Expect) {
     let green = parser::parse_bibtex(input);
     let root = bibtex::SyntaxNode::new_root(green);
     let line_index = LineIndex::new(input);
-    let output = crate::format(&root, &line_index, &Options::default());
+    let output = crate::format(&root, &line_index, options);
     expect.assert_eq(&output);
 }
 
@@ -142,3 +146,59 @@ fn test_preamble() {
         expect![[r#"@preamble{"foo bar baz"}"#]],
     );
 }
+
+#[test]
+fn test_align_fields() {
+    check_with_options(
+        r#"@article{foo, author = {Foo Bar}, title = {Baz}, journaltitle = {Qux}}"#,
+        &Options {
+            align_fields: true,
+            ..Options::default()
+        },
+        expect![[r#"
+            @article{foo,
+                author       = {Foo Bar},
+                title        = {Baz},
+                journaltitle = {Qux},
+            }"#]],
+    );
+}
+
+#[test]
+fn test_align_fields_wrap_long_lines() {
+    check_with_options(
+        r#"@article{foo, author = {Foo Bar}, abstract = {Lorem ipsum dolor sit amet, consectetur adipiscing elit. Lorem ipsum dolor sit amet.}}"#,
+        &Options {
+            align_fields: true,
+            ..Options::default()
+        },
+        expect![[r#"
+            @article{foo,
+                author   = {Foo Bar},
+                abstract = {Lorem ipsum dolor sit amet, consectetur adipiscing elit. Lorem
+                            ipsum dolor sit amet.},
+            }"#]],
+    );
+}
+
+#[test]
+fn test_field_order() {
+    check_with_options(
+        r#"@article{foo, note = {Qux}, year = {2024}, Title = {Bar}, doi = {Baz}, author = {Foo}}"#,
+        &Options {
+            field_order: ["author", "title", "year"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            ..Options::default()
+        },
+        expect![[r#"
+            @article{foo,
+                author = {Foo},
+                Title = {Bar},
+                year = {2024},
+                note = {Qux},
+                doi = {Baz},
+            }"#]],
+    );
+}
@@ -9,7 +9,7 @@ use bibtex_utils::field::{
 use rustc_hash::FxHashMap;
 use syntax::bibtex::{Entry, Field, HasName, HasType, HasValue, Value};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
 pub enum EntryKind {
     Article,
     Book,
@@ -45,6 +45,7 @@ pub enum EntryKind {
     MasterThesis,
     PhdThesis,
     TechReport,
+    #[default]
     Unknown,
 }
 
@@ -90,12 +91,6 @@ impl EntryKind {
     }
 }
 
-impl Default for EntryKind {
-    fn default() -> Self {
-        Self::Unknown
-    }
-}
-
 #[derive(Debug, Clone, Default)]
 pub struct EntryData {
     pub kind: EntryKind,
@@ -1,8 +1,9 @@
 use std::{
     io::{BufReader, Read},
     path::{Path, PathBuf},
-    process::{Child, Stdio},
+    process::{Child, ExitStatus, Stdio},
     thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
 use anyhow::Result;
@@ -33,6 +34,7 @@ pub enum BuildError {
 pub struct BuildCommand {
     program: String,
     args: Vec<String>,
+    env: Vec<(String, String)>,
     working_dir: PathBuf,
 }
 
@@ -42,10 +44,7 @@ impl BuildCommand {
             return Err(BuildError::NotFound(uri.clone()));
         };
 
-        let document = deps::parents(workspace, document)
-            .into_iter()
-            .next()
-            .unwrap_or(document);
+        let document = deps::build_root(workspace, document);
 
         let Some(document_dir) = &document.dir else {
             return Err(BuildError::NotLocal(document.uri.clone()));
@@ -55,9 +54,9 @@ impl BuildCommand {
             return Err(BuildError::NotLocal(document.uri.clone()));
         };
 
-        let config = &workspace.config().build;
-        let program = config.program.clone();
-        let args = replace_placeholders(&config.args, &[('f', path)]);
+        let Some(dir) = Path::new(path).parent().and_then(Path::to_str) else {
+            return Err(BuildError::NotLocal(document.uri.clone()));
+        };
 
         let root = ProjectRoot::walk_and_find(workspace, document_dir);
 
@@ -65,9 +64,24 @@ impl BuildCommand {
             return Err(BuildError::NotLocal(document.uri.clone()));
         };
 
+        let Ok(output_dir) = root.pdf_dir.to_file_path() else {
+            return Err(BuildError::NotLocal(document.uri.clone()));
+        };
+
+        let Some(output_dir) = output_dir.components().as_path().to_str() else {
+            return Err(BuildError::NotLocal(document.uri.clone()));
+        };
+
+        let config = &workspace.config().build;
+        let program = config.program.clone();
+        let env = config.env.clone().into_iter().collect();
+        let args =
+            replace_placeholders(&config.args, &[('f', path), ('d', dir), ('o', output_dir)]);
+
         Ok(Self {
             program,
             args,
+            env,
             working_dir,
         })
     }
@@ -88,10 +102,42 @@ impl BuildCommand {
         Ok(process)
     }
 
+    /// Waits for the compiler to exit.
+    /// If the process is still running after `timeout`, it is killed and `None` is returned.
+    pub fn wait(
+        process: &mut Child,
+        timeout: Option<Duration>,
+    ) -> std::io::Result<Option<ExitStatus>> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+        let Some(timeout) = timeout else {
+            return process.wait().map(Some);
+        };
+
+        let start = Instant::now();
+        loop {
+            if let Some(status) = process.try_wait()? {
+                return Ok(Some(status));
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                log::warn!("Compiler did not finish within {timeout:?}, killing it");
+                let _ = Self::cancel(process.id());
+                let _ = process.kill();
+                process.wait()?;
+                return Ok(None);
+            }
+
+            thread::sleep(POLL_INTERVAL.min(timeout - elapsed));
+        }
+    }
+
     #[cfg(windows)]
     fn spawn_internal(&self) -> std::io::Result<Child> {
         std::process::Command::new(&self.program)
             .args(self.args.clone())
+            .envs(self.env.clone())
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -104,6 +150,7 @@ impl BuildCommand {
         use std::os::unix::process::CommandExt;
         std::process::Command::new(&self.program)
             .args(self.args.clone())
+            .envs(self.env.clone())
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -146,3 +193,162 @@ fn track_output(
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use base_db::{BuildConfig, Config};
+    use test_utils::fixture::Fixture;
+
+    use super::BuildCommand;
+
+    fn command(program: &str, args: &[&str]) -> BuildCommand {
+        BuildCommand {
+            program: program.into(),
+            args: args.iter().copied().map(String::from).collect(),
+            env: Vec::new(),
+            working_dir: std::env::temp_dir(),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_placeholders() {
+        let mut fixture = Fixture::parse(
+            r#"
+%! main.tex
+\documentclass{article}"#,
+        );
+
+        fixture.workspace.set_config(Config {
+            build: BuildConfig {
+                args: ["%f", "%d", "%o", "-outdir=%o", "\"%d\""]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                pdf_dir: String::from("build"),
+                ..BuildConfig::default()
+            },
+            ..Config::default()
+        });
+
+        let uri = &fixture.documents[0].uri;
+        let command = BuildCommand::new(&fixture.workspace, uri).unwrap();
+        assert_eq!(
+            command.args,
+            vec![
+                "/texlab/main.tex",
+                "/texlab",
+                "/texlab/build",
+                "-outdir=/texlab/build",
+                "%d",
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_magic_root() {
+        let fixture = Fixture::parse(
+            r#"
+%! main.tex
+\documentclass{article}
+\begin{document}
+\end{document}
+
+%! chapters/intro.tex
+% Introduction
+% !TEX root = ../main.tex
+\section{Introduction}"#,
+        );
+
+        let uri = &fixture.documents[1].uri;
+        let command = BuildCommand::new(&fixture.workspace, uri).unwrap();
+        assert_eq!(
+            command.args,
+            vec![
+                "-pdf",
+                "-interaction=nonstopmode",
+                "-synctex=1",
+                "/texlab/main.tex"
+            ]
+        );
+        assert_eq!(command.working_dir, std::path::PathBuf::from("/texlab"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_magic_root_after_content() {
+        let fixture = Fixture::parse(
+            r#"
+%! main.tex
+\documentclass{article}
+
+%! chapters/intro.tex
+\section{Introduction}
+% !TEX root = ../main.tex"#,
+        );
+
+        let uri = &fixture.documents[1].uri;
+        let command = BuildCommand::new(&fixture.workspace, uri).unwrap();
+        assert_eq!(command.args.last().unwrap(), "/texlab/chapters/intro.tex");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_env() {
+        let mut command = command("env", &[]);
+        command.env = vec![("TEXINPUTS".into(), ".:./styles//:".into())];
+
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let mut process = command.spawn(sender).unwrap();
+        process.wait().unwrap();
+
+        let lines: Vec<String> = receiver.iter().collect();
+        assert!(lines.iter().any(|line| line == "TEXINPUTS=.:./styles//:"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_timeout() {
+        let (sender, _receiver) = crossbeam_channel::unbounded();
+        let mut process = command("sleep", &["10"]).spawn(sender).unwrap();
+
+        let start = Instant::now();
+        let status = BuildCommand::wait(&mut process, Some(Duration::from_millis(100))).unwrap();
+        assert_eq!(status, None);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_finished() {
+        let (sender, _receiver) = crossbeam_channel::unbounded();
+        let mut process = command("true", &[]).spawn(sender).unwrap();
+
+        let status = BuildCommand::wait(&mut process, Some(Duration::from_secs(10))).unwrap();
+        assert!(status.is_some_and(|status| status.success()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_background_child() {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let mut process = command("sh", &["-c", "sleep 10 & echo done"])
+            .spawn(sender)
+            .unwrap();
+
+        let start = Instant::now();
+        let status = BuildCommand::wait(&mut process, None).unwrap();
+        assert!(status.is_some_and(|status| status.success()));
+        assert_eq!(receiver.recv().unwrap(), "done");
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        // The background child still holds the output open.
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(100)),
+            Err(crossbeam_channel::RecvTimeoutError::Timeout)
+        );
+    }
+}
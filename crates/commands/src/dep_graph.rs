@@ -40,7 +40,7 @@ pub fn show_dependency_graph(workspace: &Workspace) -> Result<String> {
         .graphs()
         .values()
         .flat_map(|graph| &graph.edges)
-        .unique_by(|edge| (&edge.source, &edge.target, edge_label(&edge)))
+        .unique_by(|edge| (&edge.source, &edge.target, edge_label(edge)))
     {
         let source = &documents[&edge.source];
         let target = &documents[&edge.target];
@@ -56,10 +56,7 @@ impl ForwardSearch {
             .lookup(uri)
             .ok_or_else(|| ForwardSearchError::TexNotFound(uri.clone()))?;
 
-        let parent = deps::parents(workspace, child)
-            .into_iter()
-            .next()
-            .unwrap_or(child);
+        let parent = deps::build_root(workspace, child);
 
         log::debug!("[FwdSearch] root_document={}", parent.uri,);
 
@@ -137,3 +134,44 @@ impl ForwardSearch {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use base_db::{Config, SynctexConfig};
+    use test_utils::fixture::Fixture;
+
+    use super::{ForwardSearch, ForwardSearchError};
+
+    #[cfg(unix)]
+    #[test]
+    fn test_magic_root() {
+        let mut fixture = Fixture::parse(
+            r#"
+%! main.tex
+\documentclass{article}
+\begin{document}
+\end{document}
+
+%! chapters/intro.tex
+% !TEX root = ../main.tex
+\section{Introduction}"#,
+        );
+
+        fixture.workspace.set_config(Config {
+            synctex: Some(SynctexConfig {
+                program: String::from("zathura"),
+                args: vec![String::from("%p")],
+            }),
+            ..Config::default()
+        });
+
+        let uri = &fixture.documents[1].uri;
+        let error = ForwardSearch::new(&fixture.workspace, uri, None).unwrap_err();
+        assert!(matches!(
+            error,
+            ForwardSearchError::PdfNotFound(path) if path == Path::new("/texlab/main.pdf")
+        ));
+    }
+}
@@ -52,6 +52,7 @@ pub enum CompletionItemData<'a> {
     GlossaryEntry(GlossaryEntryData),
     Label(LabelData<'a>),
     Color(&'a str),
+    UserColor(&'a str),
     ColorModel(&'a str),
     File(String),
     Directory(String),
@@ -72,8 +73,9 @@ impl<'a> CompletionItemData<'a> {
             Self::Citation(data) => &data.entry.name.text,
             Self::Environment(data) => data.name,
             Self::GlossaryEntry(data) => &data.name,
-            Self::Label(data) => &data.name,
+            Self::Label(data) => data.name,
             Self::Color(name) => name,
+            Self::UserColor(name) => name,
             Self::ColorModel(name) => name,
             Self::File(name) => name,
             Self::Directory(name) => name,
@@ -98,6 +100,7 @@ impl<'a> CompletionItemData<'a> {
             Self::GlossaryEntry(_) => 1,
             Self::Label(_) => 1,
             Self::Color(_) => 1,
+            Self::UserColor(_) => 1,
             Self::ColorModel(_) => 1,
             Self::File(_) => 1,
             Self::Directory(_) => 1,
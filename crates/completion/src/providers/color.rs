@@ -1,4 +1,5 @@
 use rowan::ast::AstNode;
+use rustc_hash::FxHashSet;
 use syntax::latex;
 
 use crate::{
@@ -13,7 +14,24 @@ pub fn complete_colors<'a>(
     let (cursor, group) = find_curly_group_word(params)?;
     latex::ColorReference::cast(group.syntax().parent()?)?;
 
-    for name in COLORS {
+    let mut user_colors = FxHashSet::default();
+    let documents = params.feature.project.documents.iter();
+    for data in documents.filter_map(|document| document.data.as_tex()) {
+        for name in &data.semantics.color_definitions {
+            if !user_colors.insert(name.text.as_str()) {
+                continue;
+            }
+
+            if let Some(score) = builder.matcher.score(&name.text, &cursor.text) {
+                let data = CompletionItemData::UserColor(&name.text);
+                builder
+                    .items
+                    .push(CompletionItem::new_simple(score, cursor.range, data));
+            }
+        }
+    }
+
+    for name in COLORS.iter().filter(|name| !user_colors.contains(*name)) {
         if let Some(score) = builder.matcher.score(name, &cursor.text) {
             let data = CompletionItemData::Color(name);
             builder
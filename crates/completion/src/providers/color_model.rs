@@ -4,14 +4,18 @@ use syntax::latex;
 
 use crate::{
     CompletionItem, CompletionItemData, CompletionParams,
-    util::{CompletionBuilder, find_curly_group_word, find_curly_group_word_list},
+    util::{
+        CompletionBuilder, find_brack_group_word, find_curly_group_word, find_curly_group_word_list,
+    },
 };
 
 pub fn complete_color_models<'a>(
     params: &'a CompletionParams<'a>,
     builder: &mut CompletionBuilder<'a>,
 ) -> Option<()> {
-    let cursor = check_color_definition(params).or_else(|| check_color_definition_set(params))?;
+    let cursor = check_color_definition(params)
+        .or_else(|| check_color_definition_set(params))
+        .or_else(|| check_color_reference(params))?;
 
     for name in MODEL_NAMES {
         if let Some(score) = builder.matcher.score(name, &cursor.text) {
@@ -47,4 +51,10 @@ fn check_color_definition_set(params: &CompletionParams) -> Option<Span> {
     Some(span)
 }
 
-const MODEL_NAMES: &[&str] = &["gray", "rgb", "RGB", "HTML", "cmyk"];
+fn check_color_reference(params: &CompletionParams) -> Option<Span> {
+    let (span, group) = find_brack_group_word(params)?;
+    latex::ColorReference::cast(group.syntax().parent()?)?;
+    Some(span)
+}
+
+const MODEL_NAMES: &[&str] = &["gray", "rgb", "RGB", "HTML", "cmyk", "named"];
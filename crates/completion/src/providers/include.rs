@@ -47,7 +47,7 @@ pub fn complete_includes<'a>(
     let segment_range = if cursor.text.is_empty() {
         cursor.range
     } else {
-        let start = cursor.range.end() - cursor.text.split('/').last()?.text_len();
+        let start = cursor.range.end() - cursor.text.split('/').next_back()?.text_len();
         TextRange::new(start, cursor.range.end())
     };
 
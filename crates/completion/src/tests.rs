@@ -547,7 +547,7 @@ fn color_model_definition_simple() {
                     "gray",
                 ),
                 ColorModel(
-                    "rgb",
+                    "named",
                 ),
             ]
         "#]],
@@ -576,7 +576,25 @@ fn color_model_definition_open_brace() {
                     "gray",
                 ),
                 ColorModel(
-                    "rgb",
+                    "named",
+                ),
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn color_model_definition_word() {
+    check(
+        r#"
+%! main.tex
+\definecolor{brand}{HT}{00AAFF}
+                     |
+                    ^^"#,
+        expect![[r#"
+            [
+                ColorModel(
+                    "HTML",
                 ),
             ]
         "#]],
@@ -605,7 +623,7 @@ fn color_model_definition_set_simple() {
                     "gray",
                 ),
                 ColorModel(
-                    "rgb",
+                    "named",
                 ),
             ]
         "#]],
@@ -634,7 +652,54 @@ fn color_model_definition_set_open_brace() {
                     "gray",
                 ),
                 ColorModel(
-                    "rgb",
+                    "named",
+                ),
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn color_model_reference_simple() {
+    check(
+        r#"
+%! main.tex
+\color[]{1,0,0}
+       |"#,
+        expect![[r#"
+            [
+                ColorModel(
+                    "HTML",
+                ),
+                ColorModel(
+                    "RGB",
+                ),
+                ColorModel(
+                    "cmyk",
+                ),
+                ColorModel(
+                    "gray",
+                ),
+                ColorModel(
+                    "named",
+                ),
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn color_model_reference_word() {
+    check(
+        r#"
+%! main.tex
+\color[na]{Red}
+        |
+       ^^"#,
+        expect![[r#"
+            [
+                ColorModel(
+                    "named",
                 ),
             ]
         "#]],
@@ -729,6 +794,85 @@ fn color_open_brace() {
     );
 }
 
+#[test]
+fn color_user_defined() {
+    check(
+        r#"
+%! main.tex
+\color{bra}
+         |
+       ^^^
+\definecolor{brand}{HTML}{00AAFF}"#,
+        expect![[r#"
+            [
+                UserColor(
+                    "brand",
+                ),
+                Color(
+                    "BurntOrange",
+                ),
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn color_user_defined_duplicate() {
+    check(
+        r#"
+%! main.tex
+\include{chapter}
+\definecolor{brand}{HTML}{00AAFF}
+\color{bra}
+         |
+       ^^^
+
+%! chapter.tex
+\definecolor{brand}{HTML}{00AAFF}"#,
+        expect![[r#"
+            [
+                UserColor(
+                    "brand",
+                ),
+                Color(
+                    "BurntOrange",
+                ),
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn color_user_defined_shadowing() {
+    check(
+        r#"
+%! main.tex
+\definecolor{red}{RGB}{200,0,0}
+\color{red}
+        |
+       ^^^"#,
+        expect![[r#"
+            [
+                UserColor(
+                    "red",
+                ),
+                Color(
+                    "Red",
+                ),
+                Color(
+                    "RedOrange",
+                ),
+                Color(
+                    "RedViolet",
+                ),
+                Color(
+                    "BrickRed",
+                ),
+            ]
+        "#]],
+    );
+}
+
 #[test]
 fn component_command_simple() {
     check(
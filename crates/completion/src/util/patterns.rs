@@ -70,6 +70,34 @@ pub fn find_curly_group_word_list(
     Some((span, group))
 }
 
+pub fn find_brack_group_word(params: &CompletionParams) -> Option<(Span, latex::BrackGroupWord)> {
+    let offset = params.offset;
+    let data = params.feature.document.data.as_tex()?;
+    let root = data.root_node();
+    let tokens = root.token_at_offset(offset);
+    let token = tokens
+        .clone()
+        .find(|token| token.kind() == latex::WORD)
+        .or_else(|| tokens.left_biased())?;
+
+    let key = latex::Key::cast(token.parent()?);
+
+    let group = key
+        .as_ref()
+        .and_then(|key| key.syntax().parent())
+        .unwrap_or(token.parent()?);
+
+    let group =
+        latex::BrackGroupWord::cast(group).filter(|group| is_inside_latex_brack(group, offset))?;
+
+    let span = key.map_or_else(|| Span::empty(offset), |key| Span::from(&key));
+    Some((span, group))
+}
+
+pub fn is_inside_latex_brack(group: &impl latex::HasBrack, offset: TextSize) -> bool {
+    latex::small_range(group).contains(offset) || group.right_brack().is_none()
+}
+
 pub fn is_inside_latex_curly(group: &impl latex::HasCurly, offset: TextSize) -> bool {
     latex::small_range(group).contains(offset) || group.right_curly().is_none()
 }
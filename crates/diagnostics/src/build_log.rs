@@ -3,7 +3,7 @@ use line_index::LineCol;
 use multimap::MultiMap;
 use rowan::{TextLen, TextRange, TextSize};
 use rustc_hash::FxHashMap;
-use syntax::BuildError;
+use syntax::{BuildError, BuildLog};
 use url::Url;
 
 use crate::types::Diagnostic;
@@ -13,19 +13,40 @@ pub fn update(
     log_document: &Document,
     results: &mut FxHashMap<Url, MultiMap<Url, Diagnostic>>,
 ) -> Option<()> {
-    let mut errors = MultiMap::default();
-
     let data = log_document.data.as_log()?;
 
     let parents = deps::parents(workspace, log_document);
     let root_document = parents.iter().next()?;
 
+    let errors = collect(workspace, root_document, &data.errors)?;
+    results.insert(log_document.uri.clone(), errors);
+    Some(())
+}
+
+pub fn update_from_output(
+    workspace: &Workspace,
+    root_document: &Document,
+    log: &BuildLog,
+    results: &mut FxHashMap<Url, MultiMap<Url, Diagnostic>>,
+) -> Option<()> {
+    let errors = collect(workspace, root_document, &log.errors)?;
+    results.insert(root_document.uri.clone(), errors);
+    Some(())
+}
+
+fn collect(
+    workspace: &Workspace,
+    root_document: &Document,
+    build_errors: &[BuildError],
+) -> Option<MultiMap<Url, Diagnostic>> {
+    let mut errors = MultiMap::default();
+
     let base_path = root_document
         .path
         .as_deref()
         .and_then(|path| path.parent())?;
 
-    for error in &data.errors {
+    for error in build_errors {
         let full_path = base_path.join(&error.relative_path);
         let Ok(full_path_uri) = Url::from_file_path(&full_path) else {
             continue;
@@ -47,8 +68,7 @@ pub fn update(
         errors.insert(tex_document.uri.clone(), diagnostic);
     }
 
-    results.insert(log_document.uri.clone(), errors);
-    Some(())
+    Some(errors)
 }
 
 fn find_range_of_hint(document: &Document, error: &BuildError) -> Option<TextRange> {
@@ -45,16 +45,15 @@ impl<'a> Analyzer<'a> {
         while let Some(event) = traversal.next() {
             match event {
                 rowan::WalkEvent::Enter(node) => {
-                    if let Some(environment) = latex::Environment::cast(node.clone()) {
-                        if environment
+                    if let Some(environment) = latex::Environment::cast(node.clone())
+                        && environment
                             .begin()
                             .and_then(|begin| begin.name())
                             .and_then(|name| name.key())
                             .is_some_and(|name| verbatim_envs.contains(&name.to_string()))
-                        {
-                            traversal.skip_subtree();
-                            continue;
-                        }
+                    {
+                        traversal.skip_subtree();
+                        continue;
                     }
 
                     self.analyze_environment(node.clone())
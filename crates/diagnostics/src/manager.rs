@@ -1,9 +1,12 @@
 use base_db::{
-    Document, DocumentData, Owner, Workspace, deps::Project, util::filter_regex_patterns,
+    Document, DocumentData, Owner, Workspace,
+    deps::{self, Project},
+    util::filter_regex_patterns,
 };
 use multimap::MultiMap;
 use rowan::TextRange;
 use rustc_hash::{FxHashMap, FxHashSet};
+use syntax::BuildLog;
 use url::Url;
 
 use crate::types::Diagnostic;
@@ -14,6 +17,7 @@ pub struct Manager {
     grammar: MultiMap<Url, Diagnostic>,
     chktex: FxHashMap<Url, Vec<Diagnostic>>,
     build_log: FxHashMap<Url, MultiMap<Url, Diagnostic>>,
+    build_output: FxHashMap<Url, MultiMap<Url, Diagnostic>>,
 }
 
 impl Manager {
@@ -36,6 +40,19 @@ impl Manager {
         self.chktex.insert(uri, diagnostics);
     }
 
+    /// Updates the diagnostics extracted from the output of the last build of the given document.
+    /// The diagnostics are stored per build root, so building any document of a project
+    /// replaces the results of the previous build.
+    pub fn update_build_output(&mut self, workspace: &Workspace, uri: &Url, log: &BuildLog) {
+        let Some(document) = workspace.lookup(uri) else {
+            return;
+        };
+
+        let root_document = deps::build_root(workspace, document);
+        self.build_output.remove(&root_document.uri);
+        super::build_log::update_from_output(workspace, root_document, log, &mut self.build_output);
+    }
+
     /// Removes stale diagnostics for documents that are no longer part of the workspace.
     pub fn cleanup(&mut self, workspace: &Workspace) {
         let uris = workspace
@@ -46,6 +63,7 @@ impl Manager {
         self.grammar.retain(|uri, _| uris.contains(uri));
         self.chktex.retain(|uri, _| uris.contains(uri));
         self.build_log.retain(|uri, _| uris.contains(uri));
+        self.build_output.retain(|uri, _| uris.contains(uri));
     }
 
     /// Returns all filtered diagnostics for the given workspace.
@@ -65,6 +83,16 @@ impl Manager {
                 .extend(diagnostics.iter().cloned());
         }
 
+        for (uri, diagnostics) in self.build_output.values().flatten() {
+            let results = results.entry(uri.clone()).or_default();
+            for diagnostic in diagnostics {
+                // The log file usually reports the same errors as the compiler output.
+                if !results.contains(diagnostic) {
+                    results.push(diagnostic.clone());
+                }
+            }
+        }
+
         for (uri, diagnostics) in &self.chktex {
             if workspace
                 .lookup(uri)
@@ -1,5 +1,9 @@
 use expect_test::{Expect, expect};
 use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use url::Url;
+
+use crate::types::Diagnostic;
 
 fn check(input: &str, expect: Expect) {
     let fixture = test_utils::fixture::Fixture::parse(input);
@@ -10,6 +14,26 @@ fn check(input: &str, expect: Expect) {
     }
 
     let results = manager.get(&fixture.workspace);
+    assert_results(&results, expect);
+}
+
+fn check_build_output(input: &str, output: &str, expect: Expect) {
+    let fixture = test_utils::fixture::Fixture::parse(input);
+    let mut manager = crate::Manager::default();
+
+    for document in fixture.workspace.iter() {
+        manager.update_syntax(&fixture.workspace, document);
+    }
+
+    let log = parser::parse_build_log(output);
+    let uri = &fixture.documents[0].uri;
+    manager.update_build_output(&fixture.workspace, uri, &log);
+
+    let results = manager.get(&fixture.workspace);
+    assert_results(&results, expect);
+}
+
+fn assert_results(results: &FxHashMap<Url, Vec<Diagnostic>>, expect: Expect) {
     let results = results
         .iter()
         .filter(|(_, diags)| !diags.is_empty())
@@ -376,3 +400,122 @@ fn test_label_duplicate() {
         "#]],
     )
 }
+
+#[test]
+fn test_build_output_duplicate_of_log() {
+    check_build_output(
+        r#"
+%! main.tex
+\documentclass{article}
+\begin{document}
+\foo
+\end{document}
+
+%! main.log
+(./main.tex
+./main.tex:3: Undefined control sequence.
+l.3 \foo
+         
+)"#,
+        r#"(./main.tex
+./main.tex:3: Undefined control sequence.
+l.3 \foo
+         
+)"#,
+        expect![[r#"
+            [
+                (
+                    "file:///texlab/main.tex",
+                    [
+                        Build(
+                            41..41,
+                            BuildError {
+                                relative_path: "./main.tex",
+                                level: Error,
+                                message: "Undefined control sequence.",
+                                hint: None,
+                                line: Some(
+                                    2,
+                                ),
+                            },
+                        ),
+                    ],
+                ),
+            ]
+        "#]],
+    )
+}
+
+#[test]
+fn test_build_output_magic_root() {
+    check_build_output(
+        r#"
+%! chapters/intro.tex
+% !TEX root = ../main.tex
+\section{Introduction}
+
+%! main.tex
+\documentclass{article}
+\begin{document}
+\foo
+\end{document}"#,
+        r#"(./main.tex
+./main.tex:3: Undefined control sequence.
+l.3 \foo
+         
+)"#,
+        expect![[r#"
+            [
+                (
+                    "file:///texlab/main.tex",
+                    [
+                        Build(
+                            41..41,
+                            BuildError {
+                                relative_path: "./main.tex",
+                                level: Error,
+                                message: "Undefined control sequence.",
+                                hint: None,
+                                line: Some(
+                                    2,
+                                ),
+                            },
+                        ),
+                    ],
+                ),
+            ]
+        "#]],
+    )
+}
+
+#[test]
+fn test_build_output_replaced_by_root_build() {
+    let fixture = test_utils::fixture::Fixture::parse(
+        r#"
+%! main.tex
+\documentclass{article}
+\begin{document}
+\input{chapter}
+\end{document}
+
+%! chapter.tex
+\foo"#,
+    );
+
+    let mut manager = crate::Manager::default();
+    let output = "(./main.tex (./chapter.tex\n./chapter.tex:1: Undefined control sequence.\n))";
+    let chapter = &fixture.documents[1].uri;
+    let log = parser::parse_build_log(output);
+    manager.update_build_output(&fixture.workspace, chapter, &log);
+    assert!(!manager.get(&fixture.workspace).is_empty());
+
+    let main = &fixture.documents[0].uri;
+    let log = parser::parse_build_log("");
+    manager.update_build_output(&fixture.workspace, main, &log);
+    assert_results(
+        &manager.get(&fixture.workspace),
+        expect![[r#"
+        []
+    "#]],
+    );
+}
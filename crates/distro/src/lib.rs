@@ -13,20 +13,15 @@ use anyhow::Result;
 
 pub use self::{file_name_db::FileNameDB, language::Language};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 pub enum DistroKind {
     Texlive,
     Miktex,
     Tectonic,
+    #[default]
     Unknown,
 }
 
-impl Default for DistroKind {
-    fn default() -> Self {
-        Self::Unknown
-    }
-}
-
 #[derive(Debug, Default)]
 pub struct Distro {
     pub kind: DistroKind,
@@ -11,7 +11,7 @@ pub(super) fn find_hover<'a>(params: &HoverParams<'a>) -> Option<Hover<'a>> {
 
     let command = completion_data::included_packages(&params.feature)
         .flat_map(|package| package.commands.iter())
-        .find(|command| command.name == &name.text()[1..])?;
+        .find(|command| command.name == name.text()[1..])?;
 
     let range = name.text_range();
     let data = HoverData::Command(command);
@@ -17,9 +17,9 @@ pub(super) fn find_hover<'a>(params: &HoverParams<'a>) -> Option<Hover<'a>> {
         })?;
 
     for string in root.strings() {
-        if !string
+        if string
             .name_token()
-            .is_some_and(|token| token.text() == name.text())
+            .is_none_or(|token| token.text() != name.text())
         {
             continue;
         }
@@ -16,11 +16,11 @@ pub(super) fn find_links<'a>(
     let graph = &params.workspace.graphs()[&parent.uri];
 
     for edge in &graph.edges {
-        if edge.source == document.uri {
-            if let EdgeData::DirectLink(data) = &edge.data {
-                let target = params.workspace.lookup(&edge.target).unwrap();
-                results.push(DocumentLocation::new(target, data.link.path.range));
-            }
+        if edge.source == document.uri
+            && let EdgeData::DirectLink(data) = &edge.data
+        {
+            let target = params.workspace.lookup(&edge.target).unwrap();
+            results.push(DocumentLocation::new(target, data.link.path.range));
         }
     }
 
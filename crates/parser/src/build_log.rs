@@ -16,6 +16,10 @@ static TEX_ERROR_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new("(?m)^! ((?P<msg1>(.|\r|\n)*?)\r?\nl\\.(?P<line>\\d+)( (\\.\\.\\.)?(?P<hint>[^\r\n]+))?|(?P<msg2>[^\r\n]*))").unwrap()
 });
 
+static FILE_LINE_ERROR_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("(?m)^(?P<file>[^\r\n()]+?):(?P<line>\\d+): (?P<msg>[^\r\n]*)").unwrap()
+});
+
 static WARNING_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new("(?P<msg>(LaTeX|Package [a-zA-Z_\\-]+) Warning: [^\r\n]*?(on input line (?P<line>\\d+))?\\.)[\r\n]").unwrap()
 });
@@ -35,8 +39,9 @@ pub fn parse_build_log(log: &str) -> BuildLog {
     let tex_errors = extract_matches(&log, &ranges, &TEX_ERROR_REGEX, BuildErrorLevel::Error);
     let warnings = extract_matches(&log, &ranges, &WARNING_REGEX, BuildErrorLevel::Warning);
     let bad_boxes = extract_matches(&log, &ranges, &BAD_BOX_REGEX, BuildErrorLevel::Warning);
+    let file_line_errors = extract_file_line_errors(&log);
 
-    let errors = [tex_errors, warnings, bad_boxes].concat();
+    let errors = [tex_errors, file_line_errors, warnings, bad_boxes].concat();
     BuildLog { errors }
 }
 
@@ -85,6 +90,22 @@ fn extract_matches(
     errors
 }
 
+fn extract_file_line_errors(log: &str) -> Vec<BuildError> {
+    FILE_LINE_ERROR_REGEX
+        .captures_iter(log)
+        .map(|captures| BuildError {
+            relative_path: PathBuf::from(&captures["file"]),
+            level: BuildErrorLevel::Error,
+            message: captures["msg"].to_owned(),
+            hint: None,
+            line: captures["line"]
+                .parse::<u32>()
+                .ok()
+                .map(|line| line.saturating_sub(1)),
+        })
+        .collect()
+}
+
 fn prepare_log(log: &str) -> String {
     let mut old_lines = log.lines();
     let mut new_lines: Vec<String> = Vec::new();
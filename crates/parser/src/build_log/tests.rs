@@ -1226,3 +1226,135 @@ PDF statistics:
         "#]],
     );
 }
+
+#[test]
+fn test_file_line_error() {
+    check(
+        r#"This is pdfTeX, Version 3.141592653-2.6-1.40.25 (TeX Live 2023) (preloaded format=pdflatex)
+ restricted \write18 enabled.
+entering extended mode
+(./main.tex
+LaTeX2e <2022-11-01> patch level 1
+(/usr/share/texlive/texmf-dist/tex/latex/base/article.cls
+Document Class: article 2022/07/02 v1.4n Standard LaTeX document class
+(/usr/share/texlive/texmf-dist/tex/latex/base/size10.clo))
+(./main.aux)
+./main.tex:42: Undefined control sequence.
+l.42 \foo
+         
+./chapters/intro.tex:7: Missing $ inserted.
+<inserted text> 
+                $
+l.7 a_
+      b
+[1{/var/lib/texmf/fonts/map/pdftex/updmap/pdftex.map}] (./main.aux) )
+Output written on main.pdf (1 page, 12345 bytes).
+Transcript written on main.log."#,
+        expect![[r#"
+            BuildLog {
+                errors: [
+                    BuildError {
+                        relative_path: "./main.tex",
+                        level: Error,
+                        message: "Undefined control sequence.",
+                        hint: None,
+                        line: Some(
+                            41,
+                        ),
+                    },
+                    BuildError {
+                        relative_path: "./chapters/intro.tex",
+                        level: Error,
+                        message: "Missing $ inserted.",
+                        hint: None,
+                        line: Some(
+                            6,
+                        ),
+                    },
+                ],
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn test_file_line_error_other_extensions() {
+    check(
+        r#"(./main.ltx
+(./main.bbl
+./main.bbl:12: Missing $ inserted.
+<inserted text> 
+                $
+l.12 a_
+       b
+)
+./preamble.def:3: Undefined control sequence.
+l.3 \foo
+         
+)"#,
+        expect![[r#"
+            BuildLog {
+                errors: [
+                    BuildError {
+                        relative_path: "./main.bbl",
+                        level: Error,
+                        message: "Missing $ inserted.",
+                        hint: None,
+                        line: Some(
+                            11,
+                        ),
+                    },
+                    BuildError {
+                        relative_path: "./preamble.def",
+                        level: Error,
+                        message: "Undefined control sequence.",
+                        hint: None,
+                        line: Some(
+                            2,
+                        ),
+                    },
+                ],
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn test_warning_in_output() {
+    check(
+        r#"(./main.tex
+LaTeX2e <2022-11-01> patch level 1
+(./main.aux)
+
+LaTeX Warning: Reference `fig:foo' on page 1 undefined on input line 12.
+
+[1] (./main.aux)
+
+LaTeX Warning: There were undefined references.
+
+ )
+Output written on main.pdf (1 page, 12345 bytes)."#,
+        expect![[r#"
+            BuildLog {
+                errors: [
+                    BuildError {
+                        relative_path: "./main.tex",
+                        level: Warning,
+                        message: "LaTeX Warning: Reference `fig:foo' on page 1 undefined on input line 12.",
+                        hint: None,
+                        line: Some(
+                            11,
+                        ),
+                    },
+                    BuildError {
+                        relative_path: "./main.tex",
+                        level: Warning,
+                        message: "LaTeX Warning: There were undefined references.",
+                        hint: None,
+                        line: None,
+                    },
+                ],
+            }
+        "#]],
+    );
+}
@@ -322,15 +322,12 @@ impl<'a> Parser<'a> {
         self.builder.start_node(BRACK_GROUP_WORD.into());
         self.eat();
         self.trivia();
-        match self.peek() {
-            Some(Token::Word | Token::Pipe) => {
-                self.key_with_opts(KeyOptions {
-                    allow_eq: true,
-                    allow_bracks: false,
-                    allow_parens: true,
-                });
-            }
-            Some(_) | None => {}
+        if let Some(Token::Word | Token::Pipe) = self.peek() {
+            self.key_with_opts(KeyOptions {
+                allow_eq: true,
+                allow_bracks: false,
+                allow_parens: true,
+            });
         }
         self.expect(Token::RBrack);
         self.builder.finish_node();
@@ -1120,7 +1117,12 @@ impl<'a> Parser<'a> {
         self.eat();
         self.trivia();
 
-        if self.lexer.peek() == Some(Token::LCurly) {
+        if self.lexer.peek() == Some(Token::LBrack) {
+            self.brack_group_word();
+            if self.lexer.peek() == Some(Token::LCurly) {
+                self.curly_group();
+            }
+        } else if self.lexer.peek() == Some(Token::LCurly) {
             self.curly_group_word();
         }
 
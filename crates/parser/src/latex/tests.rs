@@ -465,6 +465,34 @@ fn test_color_reference_simple() {
     );
 }
 
+#[test]
+fn test_color_reference_model() {
+    check(
+        r#"\color[rgb]{1,0,0}"#,
+        expect![[r#"
+        ROOT@0..18
+          PREAMBLE@0..18
+            COLOR_REFERENCE@0..18
+              COMMAND_NAME@0..6 "\\color"
+              BRACK_GROUP_WORD@6..11
+                L_BRACK@6..7 "["
+                KEY@7..10
+                  WORD@7..10 "rgb"
+                R_BRACK@10..11 "]"
+              CURLY_GROUP@11..18
+                L_CURLY@11..12 "{"
+                TEXT@12..17
+                  WORD@12..13 "1"
+                  COMMA@13..14 ","
+                  WORD@14..15 "0"
+                  COMMA@15..16 ","
+                  WORD@16..17 "0"
+                R_CURLY@17..18 "}"
+
+    "#]],
+    );
+}
+
 #[test]
 fn test_color_set_definition_error1() {
     check(
@@ -19,7 +19,7 @@ mod v483 {
     pub fn parse_latexmkrc(input: &str, src_dir: &Path) -> std::io::Result<LatexmkrcData> {
         let temp_dir = tempdir()?;
         let non_existent_tex = temp_dir.path().join("NONEXISTENT.tex");
-        std::fs::write(temp_dir.path().join(".latexmkrc"), &append_texinputs(input))?;
+        std::fs::write(temp_dir.path().join(".latexmkrc"), append_texinputs(input))?;
 
         // Run `latexmk -dir-report $TMPDIR/NONEXISTENT.tex` to obtain out_dir
         // and aux_dir values. We pass nonexistent file to prevent latexmk from
@@ -85,7 +85,7 @@ mod v484 {
 
     pub fn parse_latexmkrc(input: &str, src_dir: &Path) -> std::io::Result<LatexmkrcData> {
         let temp_dir = tempdir()?;
-        std::fs::write(temp_dir.path().join(".latexmkrc"), &append_texinputs(input))?;
+        std::fs::write(temp_dir.path().join(".latexmkrc"), append_texinputs(input))?;
 
         // Create an empty dummy TeX file to let latexmk continue
         std::fs::write(temp_dir.path().join("dummy.tex"), "")?;
@@ -198,7 +198,7 @@ fn parse_texinputs<'a>(
     let mut file_name_db = FileNameDB::default();
 
     let mut lines = stdout.lines();
-    while let Some(line) = lines.next() {
+    for line in lines.by_ref() {
         if let Some(inputs) = line.strip_prefix("TEXINPUTS=") {
             paths.extend(env::split_paths(inputs));
         } else if let Some(inputs) = line.strip_prefix("BIBINPUTS=") {
@@ -16,7 +16,7 @@ fn label_has_prefix(pref_info: &PrefixInformation, label: &tex::Label) -> Option
         _ => pref_info.ref_prefixes.iter(),
     }
     .find_map(|(k, v)| {
-        if k == &label.cmd.clone().unwrap_or(String::new()) {
+        if k == &label.cmd.clone().unwrap_or_default() {
             Some(v)
         } else {
             None
@@ -32,7 +32,7 @@ fn find_prefix_in_any(
 ) -> Option<String> {
     let project = &builder.params.feature.project;
     queries::objects_with_name::<tex::Label>(project, name)
-        .find_map(|(_, label)| label_has_prefix(&pref_info, label))
+        .find_map(|(_, label)| label_has_prefix(pref_info, label))
 }
 
 pub(super) fn prepare_rename(params: &RenameParams) -> Option<Span> {
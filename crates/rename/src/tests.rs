@@ -17,10 +17,7 @@ fn check_with_syntax_config(config: SyntaxConfig, input: &str) {
     for spec in &fixture.documents {
         if !spec.ranges.is_empty() {
             let document = fixture.workspace.lookup(&spec.uri).unwrap();
-            expected.insert(
-                document,
-                spec.ranges.iter().map(|r| r.clone().into()).collect(),
-            );
+            expected.insert(document, spec.ranges.iter().map(|r| (*r).into()).collect());
         }
     }
     let (feature, offset) = fixture.make_params().unwrap();
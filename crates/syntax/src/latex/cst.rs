@@ -250,8 +250,8 @@ impl PartialEq for Key {
 
 impl Eq for Key {}
 
-impl ToString for Key {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut buf = String::new();
         for token in self
             .syntax()
@@ -265,8 +265,7 @@ impl ToString for Key {
             }
         }
 
-        buf = String::from(buf.trim());
-        buf
+        write!(f, "{}", buf.trim())
     }
 }
 
@@ -717,6 +716,14 @@ impl ColorReference {
     pub fn name(&self) -> Option<CurlyGroupWord> {
         self.syntax().children().find_map(CurlyGroupWord::cast)
     }
+
+    pub fn model(&self) -> Option<BrackGroupWord> {
+        self.syntax().children().find_map(BrackGroupWord::cast)
+    }
+
+    pub fn spec(&self) -> Option<CurlyGroup> {
+        self.syntax().children().find_map(CurlyGroup::cast)
+    }
 }
 
 cst_node!(GlossaryEntryReference, GLOSSARY_ENTRY_REFERENCE);
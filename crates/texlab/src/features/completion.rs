@@ -111,6 +111,10 @@ impl<'a> ItemBuilder<'a> {
             CompletionItemData::Color(name) => {
                 self.convert_color(&mut result, range, name);
             }
+            CompletionItemData::UserColor(name) => {
+                self.convert_color(&mut result, range, name);
+                result.detail = Some(String::from("user-defined"));
+            }
             CompletionItemData::ColorModel(name) => {
                 self.convert_color_model(&mut result, range, name);
             }
@@ -9,14 +9,55 @@ pub fn format_bibtex_internal(
     options: &lsp_types::FormattingOptions,
 ) -> Option<Vec<lsp_types::TextEdit>> {
     let data = document.data.as_bib()?;
+    let config = &workspace.config().formatting;
     let options = bibfmt::Options {
         insert_spaces: options.insert_spaces,
-        line_length: workspace.config().formatting.line_length,
+        line_length: config.line_length,
         tab_size: options.tab_size as usize,
+        align_fields: config.bibtex.align_fields,
+        field_order: config.bibtex.field_order.clone(),
     };
 
     let output = bibfmt::format(&data.root_node(), &document.line_index, &options);
+    if output == document.text {
+        return Some(Vec::new());
+    }
+
     let end = document.line_index.line_col_lsp(document.text.text_len())?;
     let range = lsp_types::Range::new(lsp_types::Position::new(0, 0), end);
     Some(vec![lsp_types::TextEdit::new(range, output)])
 }
+
+#[cfg(test)]
+mod tests {
+    use base_db::{Owner, Workspace};
+    use distro::Language;
+    use line_index::LineCol;
+    use url::Url;
+
+    use super::format_bibtex_internal;
+
+    #[test]
+    fn test_already_formatted() {
+        let uri = Url::parse("file:///texlab/main.bib").unwrap();
+        let options = lsp_types::FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        };
+
+        let mut workspace = Workspace::default();
+        let text = "@article{foo,\n    author = {Bar},\n}\n";
+        workspace.open(
+            uri.clone(),
+            text.into(),
+            Language::Bib,
+            Owner::Client,
+            LineCol { line: 0, col: 0 },
+        );
+
+        let document = workspace.lookup(&uri).unwrap();
+        let edits = format_bibtex_internal(&workspace, document, &options).unwrap();
+        assert_eq!(edits, Vec::new());
+    }
+}
@@ -49,7 +49,7 @@ pub fn format_with_latexindent(
         None
     } else if new_text == *old_text {
         // No edits needed
-        return Some(Vec::new());
+        Some(Vec::new())
     } else {
         let line_index = &document.line_index;
         let start = lsp_types::Position::new(0, 0);
@@ -73,10 +73,10 @@ fn build_arguments(config: &LatexIndentConfig, target_file: &Path) -> Vec<String
         args.push("--modifylinebreaks".to_string());
     }
 
-    if let Some(replacement_flag) = &config.replacement {
-        if ["-r", "-rv", "-rr"].contains(&replacement_flag.as_str()) {
-            args.push(replacement_flag.clone());
-        }
+    if let Some(replacement_flag) = &config.replacement
+        && ["-r", "-rv", "-rr"].contains(&replacement_flag.as_str())
+    {
+        args.push(replacement_flag.clone());
     }
 
     args.push(target_file.display().to_string());
@@ -32,6 +32,10 @@ pub fn format_with_texfmt(
 
         let output = child.wait_with_output().ok()?;
         let new_text = String::from_utf8(output.stdout).ok()?;
+        if new_text == document.text {
+            return Some(Vec::new());
+        }
+
         let range = document
             .line_index
             .line_col_lsp_range(TextRange::new(0.into(), document.text.text_len()))?;
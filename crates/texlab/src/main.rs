@@ -81,7 +81,7 @@ fn main() -> Result<()> {
 
             let Some(line) = opts
                 .line
-                .or_else(|| opts.line0)
+                .or(opts.line0)
                 .or_else(|| opts.line1.and_then(|l| l.checked_sub(1)))
             else {
                 if opts.line1.is_some() {
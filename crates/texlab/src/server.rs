@@ -42,7 +42,7 @@ use self::{
         ForwardSearchRequest, ForwardSearchResult, ForwardSearchStatus, TextWithRange,
     },
     options::{Options, StartupOptions},
-    progress::ProgressReporter,
+    progress::{BuildProgress, ProgressReporter},
 };
 
 #[derive(Debug)]
@@ -52,6 +52,7 @@ enum InternalMessage {
     FileEvent(Vec<DebouncedEvent>),
     Diagnostics,
     ChktexFinished(Url, Vec<diagnostics::Diagnostic>),
+    BuildFinished(Url, syntax::BuildLog),
     ForwardSearch(Url, Option<Position>),
     InverseSearch(TextDocumentPositionParams),
 }
@@ -185,13 +186,10 @@ impl Server {
                 ..Default::default()
             }),
             inlay_hint_provider: Some(OneOf::Left(true)),
-            experimental: Some(Value::Object(Map::from_iter(
-                [
-                    ("textDocumentBuild".to_string(), Value::Bool(true)),
-                    ("textDocumentForwardSearch".to_string(), Value::Bool(true)),
-                ]
-                .into_iter(),
-            ))),
+            experimental: Some(Value::Object(Map::from_iter([
+                ("textDocumentBuild".to_string(), Value::Bool(true)),
+                ("textDocumentForwardSearch".to_string(), Value::Bool(true)),
+            ]))),
             ..ServerCapabilities::default()
         }
     }
@@ -697,9 +695,12 @@ impl Server {
         let client = self.client.clone();
 
         let fwd_search_after = workspace.config().build.forward_search_after;
+        let timeout = workspace.config().build.timeout;
 
         let (sender, receiver) = crossbeam_channel::unbounded();
-        self.redirect_build_log(receiver);
+        let (progress_sender, progress_receiver) = crossbeam_channel::bounded(1);
+        let (started_sender, started_receiver) = crossbeam_channel::bounded(1);
+        self.redirect_build_log(uri.clone(), receiver, progress_receiver, started_receiver);
 
         let command = BuildCommand::new(&workspace, &uri);
         let internal = self.internal_tx.clone();
@@ -707,48 +708,70 @@ impl Server {
         let pending_builds = Arc::clone(&self.pending_builds);
 
         self.pool.execute(move || {
-            let guard = LOCK.lock();
+            // Builds triggered on save wait for the running build so that the latest state is built.
+            let guard = match &id {
+                Some(id) => {
+                    let Some(guard) = LOCK.try_lock() else {
+                        log::info!("Skipping build of \"{uri}\" because another build is running");
+                        let result = BuildResult::from(BuildStatus::Busy);
+                        let response = lsp_server::Response::new_ok(id.clone(), result);
+                        let _ = client.send_response(response);
+                        return;
+                    };
+
+                    guard
+                }
+                None => LOCK.lock(),
+            };
 
             let progress_reporter = if progress {
                 let token = NEXT_TOKEN.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-                Some(ProgressReporter::new(
+                let reporter = Arc::new(ProgressReporter::new(
                     client.clone(),
                     token,
                     &params.text_document.uri,
-                ))
+                ));
+
+                let _ = progress_sender.send(Arc::clone(&reporter));
+                Some(reporter)
             } else {
                 None
             };
 
-            let status = command
+            let result = command
                 .and_then(|command| {
                     let mut process = command.spawn(sender)?;
+                    let _ = started_sender.send(());
                     let pid = process.id();
                     pending_builds.lock().insert(pid);
-                    let result = process.wait();
+                    let result = BuildCommand::wait(&mut process, timeout);
 
-                    let status = if pending_builds.lock().remove(&pid) {
-                        if result?.success() {
-                            BuildStatus::Success
-                        } else {
-                            BuildStatus::Error
+                    let result = if pending_builds.lock().remove(&pid) {
+                        match result? {
+                            Some(status) => BuildResult::from(status),
+                            None => BuildResult::from(BuildStatus::Cancelled),
                         }
                     } else {
-                        BuildStatus::Cancelled
+                        BuildResult::from(BuildStatus::Cancelled)
                     };
 
-                    Ok(status)
+                    Ok(result)
                 })
                 .unwrap_or_else(|why| {
                     log::error!("Failed to compile document \"{uri}\": {why}");
-                    BuildStatus::Failure
+                    BuildResult::from(BuildStatus::Failure)
                 });
 
-            drop(progress_reporter);
+            // The log thread may still be reading output from background processes,
+            // so the progress is ended here before the response is sent.
+            if let Some(reporter) = progress_reporter {
+                reporter.end();
+            }
+
             drop(guard);
 
+            let status = result.status;
             if let Some(id) = id {
-                let result = BuildResult { status };
                 let _ = client.send_response(lsp_server::Response::new_ok(id, result));
             }
 
@@ -760,15 +783,46 @@ impl Server {
         Ok(())
     }
 
-    fn redirect_build_log(&self, receiver: Receiver<String>) {
+    fn redirect_build_log(
+        &self,
+        uri: Url,
+        receiver: Receiver<String>,
+        progress_receiver: Receiver<Arc<ProgressReporter>>,
+        started_receiver: Receiver<()>,
+    ) {
         let client = self.client.clone();
+        let internal = self.internal_tx.clone();
         self.pool.execute(move || {
             let typ = MessageType::LOG;
+            let mut output = String::new();
+            let mut progress = BuildProgress::default();
+            let mut progress_reporter = None;
             for message in receiver {
+                output.push_str(&message);
+                output.push('\n');
+
+                if progress_reporter.is_none() {
+                    progress_reporter = progress_receiver.try_recv().ok();
+                }
+
+                if let Some(reporter) = &progress_reporter
+                    && progress.update(&message)
+                    && let Some(text) = progress.message()
+                {
+                    reporter.report(text);
+                }
+
                 client
                     .send_notification::<LogMessage>(LogMessageParams { message, typ })
                     .unwrap();
             }
+
+            // Builds that print nothing still clear the diagnostics of the previous build.
+            // The channel is closed without a message if the compiler was never started.
+            if started_receiver.recv().is_ok() {
+                let log = parser::parse_build_log(&output);
+                let _ = internal.send(InternalMessage::BuildFinished(uri, log));
+            }
         });
     }
 
@@ -887,6 +941,7 @@ impl Server {
         CleanCommand::new(&workspace, document, target)
     }
 
+    #[allow(clippy::mutable_key_type)]
     fn change_environment(&self, params: ExecuteCommandParams) -> Result<ApplyWorkspaceEditParams> {
         let workspace = self.workspace.read();
         let params = self.parse_command_params::<RenameParams>(params.arguments)?;
@@ -1105,6 +1160,13 @@ impl Server {
                             self.diagnostic_manager.update_chktex(uri, diagnostics);
                             self.publish_diagnostics()?;
                         }
+                        InternalMessage::BuildFinished(uri, log) => {
+                            let workspace = self.workspace.read();
+                            self.diagnostic_manager
+                                .update_build_output(&workspace, &uri, &log);
+                            drop(workspace);
+                            self.publish_diagnostics()?;
+                        }
                         InternalMessage::ForwardSearch(uri, position) => {
                             self.forward_search(None, uri, position)?;
                         }
@@ -1215,9 +1277,9 @@ fn handle_path_change(
             !affected_uris.is_empty()
         }
         PathChangeKind::CreatedOrModified => {
-            if !workspace
+            if workspace
                 .lookup_file(path)
-                .map_or(true, |doc| doc.owner == Owner::Server)
+                .is_some_and(|doc| doc.owner != Owner::Server)
             {
                 return false;
             }
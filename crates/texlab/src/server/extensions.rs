@@ -1,5 +1,7 @@
 #![allow(non_camel_case_types)]
 
+use std::process::ExitStatus;
+
 use commands::ForwardSearchError;
 use lsp_types::{Position, Range, TextDocumentIdentifier, TextDocumentPositionParams};
 use serde::{Deserialize, Serialize};
@@ -28,6 +30,31 @@ pub struct BuildParams {
 #[serde(rename_all = "camelCase")]
 pub struct BuildResult {
     pub status: BuildStatus,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+}
+
+impl From<BuildStatus> for BuildResult {
+    fn from(status: BuildStatus) -> Self {
+        Self {
+            status,
+            exit_code: None,
+        }
+    }
+}
+
+impl From<ExitStatus> for BuildResult {
+    fn from(status: ExitStatus) -> Self {
+        Self {
+            status: if status.success() {
+                BuildStatus::Success
+            } else {
+                BuildStatus::Error
+            },
+            exit_code: status.code(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize_repr, Deserialize_repr)]
@@ -37,6 +64,7 @@ pub enum BuildStatus {
     Error = 1,
     Failure = 2,
     Cancelled = 3,
+    Busy = 4,
 }
 
 pub struct ForwardSearchRequest;
@@ -90,3 +118,34 @@ pub struct TextWithRange {
     pub text: String,
     pub range: Range,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildResult, BuildStatus};
+
+    #[cfg(unix)]
+    #[test]
+    fn test_build_result_exit_code() {
+        let status = std::process::Command::new("sh")
+            .args(["-c", "exit 12"])
+            .status()
+            .unwrap();
+
+        let result = BuildResult::from(status);
+        assert_eq!(result.status, BuildStatus::Error);
+        assert_eq!(result.exit_code, Some(12));
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!({ "status": 1, "exitCode": 12 })
+        );
+    }
+
+    #[test]
+    fn test_build_result_without_exit_code() {
+        let result = BuildResult::from(BuildStatus::Cancelled);
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!({ "status": 3 })
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
 use regex::Regex;
@@ -17,42 +18,33 @@ pub struct Options {
     pub chktex: ChktexOptions,
     pub symbols: SymbolOptions,
     pub latexindent: LatexindentOptions,
+    pub bibtex_formatting: BibtexFormattingOptions,
     pub forward_search: ForwardSearchOptions,
     pub completion: CompletionOptions,
     pub inlay_hints: InlayHintOptions,
     pub experimental: ExperimentalOptions,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum BibtexFormatter {
     None,
+    #[default]
     Texlab,
     Latexindent,
     TexFmt,
 }
 
-impl Default for BibtexFormatter {
-    fn default() -> Self {
-        Self::Texlab
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum LatexFormatter {
     None,
     Texlab,
+    #[default]
     Latexindent,
     TexFmt,
 }
 
-impl Default for LatexFormatter {
-    fn default() -> Self {
-        Self::Latexindent
-    }
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
@@ -62,6 +54,14 @@ pub struct LatexindentOptions {
     pub replacement: Option<String>,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BibtexFormattingOptions {
+    pub align_fields: bool,
+    pub field_order: Vec<String>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
@@ -75,6 +75,8 @@ pub struct BuildOptions {
     pub pdf_directory: Option<String>,
     pub filename: Option<String>,
     pub use_file_list: bool,
+    pub timeout: Option<u64>,
+    pub env: FxHashMap<String, String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
@@ -178,17 +180,12 @@ pub struct CompletionOptions {
     pub matcher: CompletionMatcher,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum CompletionMatcher {
     Fuzzy,
+    #[default]
     FuzzyIgnoreCase,
     Prefix,
     PrefixIgnoreCase,
 }
-
-impl Default for CompletionMatcher {
-    fn default() -> Self {
-        Self::FuzzyIgnoreCase
-    }
-}
@@ -1,8 +1,12 @@
 use lsp_types::{
     NumberOrString, ProgressParams, ProgressParamsValue, WorkDoneProgress, WorkDoneProgressBegin,
-    WorkDoneProgressCreateParams, WorkDoneProgressEnd, notification::Progress,
-    request::WorkDoneProgressCreate,
+    WorkDoneProgressCreateParams, WorkDoneProgressEnd, WorkDoneProgressReport,
+    notification::Progress, request::WorkDoneProgressCreate,
 };
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
 
 use crate::LspClient;
 
@@ -10,16 +14,12 @@ use crate::LspClient;
 pub struct ProgressReporter {
     client: LspClient,
     token: i32,
+    ended: AtomicBool,
 }
 
 impl Drop for ProgressReporter {
     fn drop(&mut self) {
-        let _ = self.client.send_notification::<Progress>(ProgressParams {
-            token: NumberOrString::Number(self.token),
-            value: ProgressParamsValue::WorkDone(WorkDoneProgress::End(WorkDoneProgressEnd {
-                message: None,
-            })),
-        });
+        self.end();
     }
 }
 
@@ -39,7 +39,11 @@ impl ProgressReporter {
             })),
         });
 
-        Self { client, token }
+        Self {
+            client,
+            token,
+            ended: AtomicBool::new(false),
+        }
     }
 
     pub fn new_inputs_progress(client: LspClient, token: i32) -> Self {
@@ -57,6 +61,187 @@ impl ProgressReporter {
             })),
         });
 
-        Self { client, token }
+        Self {
+            client,
+            token,
+            ended: AtomicBool::new(false),
+        }
+    }
+
+    /// Reports a new message unless the progress has already ended.
+    pub fn report(&self, message: String) {
+        if self.ended.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let _ = self.client.send_notification::<Progress>(ProgressParams {
+            token: NumberOrString::Number(self.token),
+            value: ProgressParamsValue::WorkDone(WorkDoneProgress::Report(
+                WorkDoneProgressReport {
+                    message: Some(message),
+                    cancellable: Some(false),
+                    percentage: None,
+                },
+            )),
+        });
+    }
+
+    /// Ends the progress. Subsequent calls and reports are ignored.
+    pub fn end(&self) {
+        if self.ended.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let _ = self.client.send_notification::<Progress>(ProgressParams {
+            token: NumberOrString::Number(self.token),
+            value: ProgressParamsValue::WorkDone(WorkDoneProgress::End(WorkDoneProgressEnd {
+                message: None,
+            })),
+        });
+    }
+}
+
+static RUN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^Run number (?P<run>\d+) of rule").unwrap());
+
+static PAGE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[(?P<page>\d+)(\]|\{|<|\s|$)").unwrap());
+
+/// Tracks the progress of a build by looking at the output of `latexmk` and the TeX engine.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct BuildProgress {
+    pub run: Option<u32>,
+    pub page: Option<u32>,
+}
+
+impl BuildProgress {
+    /// Updates the progress with the given line of output.
+    /// Returns `true` if the line contained a new run or page marker.
+    pub fn update(&mut self, line: &str) -> bool {
+        let old = *self;
+        if let Some(captures) = RUN_REGEX.captures(line) {
+            self.run = captures["run"].parse().ok();
+            self.page = None;
+        }
+
+        if let Some(page) = PAGE_REGEX
+            .captures_iter(line)
+            .filter(|captures| is_page_marker(line, captures.get(0).unwrap().start()))
+            .filter_map(|captures| captures["page"].parse().ok())
+            .last()
+        {
+            self.page = Some(page);
+        }
+
+        *self != old
+    }
+
+    pub fn message(&self) -> Option<String> {
+        match (self.run, self.page) {
+            (Some(run), Some(page)) => Some(format!("Run {run}, page {page}")),
+            (Some(run), None) => Some(format!("Run {run}")),
+            (None, Some(page)) => Some(format!("Page {page}")),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Page markers start a line or follow the end of another page or file.
+/// This skips brackets in source lines that TeX echoes in error messages.
+fn is_page_marker(line: &str, start: usize) -> bool {
+    let prefix = line[..start].trim_end();
+    prefix.is_empty() || prefix.ends_with(']') || prefix.ends_with(')')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use lsp_server::{Message, Response};
+
+    use super::{BuildProgress, ProgressReporter};
+    use crate::LspClient;
+
+    #[test]
+    fn test_reporter_end() {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let client = LspClient::new(sender);
+
+        let responder = {
+            let client = client.clone();
+            thread::spawn(move || {
+                let Message::Request(request) = receiver.recv().unwrap() else {
+                    panic!("expected a request to create the progress");
+                };
+
+                let response = Response::new_ok(request.id, ());
+                client.recv_response(response).unwrap();
+                receiver
+            })
+        };
+
+        let uri = "file:///texlab/main.tex".parse().unwrap();
+        let reporter = ProgressReporter::new(client, 1, &uri);
+        let receiver = responder.join().unwrap();
+
+        reporter.report("Run 1".into());
+        reporter.end();
+        reporter.report("Run 2".into());
+        drop(reporter);
+
+        let values = receiver
+            .try_iter()
+            .map(|message| match message {
+                Message::Notification(notification) => notification.params["value"]["kind"]
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+                _ => panic!("expected a progress notification"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, vec!["begin", "report", "end"]);
+    }
+
+    #[test]
+    fn test_build_progress() {
+        let mut progress = BuildProgress::default();
+        assert!(!progress.update("This is pdfTeX, Version 3.141592653-2.6-1.40.25"));
+        assert_eq!(progress.message(), None);
+
+        assert!(progress.update("Run number 1 of rule 'pdflatex'"));
+        assert_eq!(progress.message().as_deref(), Some("Run 1"));
+
+        assert!(progress.update("[1{/var/lib/texmf/fonts/map/pdftex/updmap/pdftex.map}] [2]"));
+        assert_eq!(progress.message().as_deref(), Some("Run 1, page 2"));
+
+        assert!(progress.update("[3 <./figure.png>]"));
+        assert_eq!(progress.message().as_deref(), Some("Run 1, page 3"));
+
+        assert!(!progress.update("Overfull \\hbox (1.5pt too wide) in paragraph at lines 3--4"));
+
+        assert!(progress.update("Run number 2 of rule 'pdflatex'"));
+        assert_eq!(progress.message().as_deref(), Some("Run 2"));
+    }
+
+    #[test]
+    fn test_build_progress_error_context() {
+        let mut progress = BuildProgress::default();
+        assert!(progress.update("[1] [2]"));
+        assert_eq!(progress.message().as_deref(), Some("Page 2"));
+
+        assert!(!progress.update("l.12 see \\cite[3]{x}"));
+        assert!(!progress.update("\\item[7] foo"));
+        assert_eq!(progress.message().as_deref(), Some("Page 2"));
+
+        assert!(progress.update(" [3]"));
+        assert_eq!(progress.message().as_deref(), Some("Page 3"));
+    }
+
+    #[test]
+    fn test_build_progress_page_without_run() {
+        let mut progress = BuildProgress::default();
+        assert!(progress.update("(./main.aux) [1"));
+        assert_eq!(progress.message().as_deref(), Some("Page 1"));
     }
 }
@@ -7,15 +7,15 @@ pub mod to_proto;
 pub use self::client_flags::ClientFlags;
 
 pub fn normalize_uri(uri: &mut url::Url) {
-    if let Some(mut segments) = uri.path_segments() {
-        if let Some(mut path) = segments.next().and_then(fix_drive_letter) {
-            for segment in segments {
-                path.push('/');
-                path.push_str(segment);
-            }
-
-            uri.set_path(&path);
+    if let Some(mut segments) = uri.path_segments()
+        && let Some(mut path) = segments.next().and_then(fix_drive_letter)
+    {
+        for segment in segments {
+            path.push('/');
+            path.push_str(segment);
         }
+
+        uri.set_path(&path);
     }
 
     uri.set_path(&normalize_percent_encoding(uri.path()));
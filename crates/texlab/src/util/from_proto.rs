@@ -271,6 +271,8 @@ pub fn config(value: Options) -> Config {
         .unwrap_or_else(|| config.build.pdf_dir.clone());
 
     config.build.output_filename = value.build.filename;
+    config.build.timeout = value.build.timeout.map(Duration::from_millis);
+    config.build.env = value.build.env;
 
     config.diagnostics.allowed_patterns = value
         .diagnostics
@@ -316,6 +318,8 @@ pub fn config(value: Options) -> Config {
     config.formatting.latex_indent.local = value.latexindent.local;
     config.formatting.latex_indent.modify_line_breaks = value.latexindent.modify_line_breaks;
     config.formatting.latex_indent.replacement = value.latexindent.replacement;
+    config.formatting.bibtex.align_fields = value.bibtex_formatting.align_fields;
+    config.formatting.bibtex.field_order = value.bibtex_formatting.field_order;
 
     config.synctex = value
         .forward_search
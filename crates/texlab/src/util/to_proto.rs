@@ -156,7 +156,7 @@ pub fn diagnostic(
                 .line_col_lsp_range(*range)?;
 
             let message = format!("conflicting {object} defined here");
-            let location = lsp_types::Location::new(self::uri(&uri), range);
+            let location = lsp_types::Location::new(self::uri(uri), range);
             items.push(lsp_types::DiagnosticRelatedInformation { location, message });
         }
 
@@ -413,6 +413,7 @@ pub fn document_symbol_response(
     }
 }
 
+#[allow(clippy::mutable_key_type)]
 pub fn workspace_edit(result: RenameResult, new_name: &str) -> lsp_types::WorkspaceEdit {
     let mut changes = HashMap::default();
     for (document, ranges) in result.changes {
@@ -420,12 +421,10 @@ pub fn workspace_edit(result: RenameResult, new_name: &str) -> lsp_types::Worksp
         ranges
             .into_iter()
             .filter_map(|info| {
-                document.line_index.line_col_lsp_range(info.range).map(|i| {
-                    (
-                        i,
-                        info.prefix.map_or(new_name.into(), |p| p + new_name.into()),
-                    )
-                })
+                document
+                    .line_index
+                    .line_col_lsp_range(info.range)
+                    .map(|i| (i, info.prefix.map_or(new_name.into(), |p| p + new_name)))
             })
             .for_each(|(range, new_name)| edits.push(lsp_types::TextEdit::new(range, new_name)));
 